@@ -3,145 +3,337 @@
 
 use std::time::Instant;
 use std::collections::HashMap;
-use std::num::Wrapping;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+// Initial capacity for our hashmap when we create an instance with new constructor.
+// Kept a power of two so that the index can be masked instead of taking a modulo.
+const INITIAL_CAPACITY: usize = 16;
+
+// Maximum load factor before we grow the table. Borrowed from std's
+// DefaultResizePolicy: at 90.9% occupancy linear probing still has short chains,
+// so we resize before the table ever runs full and degrades to O(n) probes.
+const MAX_LOAD_FACTOR: f64 = 0.909;
+
+// State of a single slot in the table. Backward-shift deletion keeps every probe
+// chain contiguous, so a removed key is pulled out rather than tombstoned and two
+// states are enough: a slot either holds a live pair or is empty.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+enum SlotState {
+    // Holds no pair; a probe that reaches it can stop, the key is absent.
+    #[default]
+    Empty,
+    // Currently holds a live pair.
+    Occupied
+}
 
-// Initial capacity for our hashmap when we create an instance with new constructor
-const INITIAL_CAPACITY: usize = 15;  
- 
 #[derive(Default, Clone, Debug, PartialEq, Copy)]
 struct Pair<K, V> {
     key: K,
     value: V,
-    // Will be very helpful because we want to track the pair to know
-    // whether the key-value pair occupies the index or not. 
-    is_occupied: bool 
+    // Tracks whether the slot is empty or live so both insertion and lookup can
+    // follow the probe chain correctly.
+    state: SlotState
 }
 
-// Structure of our Hashmap. 
+// Structure of our Hashmap.
 // Storing all Key-Value associatives in Vec so that we can do any operation with the data.
+//
+// The table is generic over a `BuildHasher` so callers can swap the hash algorithm.
+// It defaults to `RandomState`, a per-instance keyed SipHash, which is why two maps
+// in the same process will not share a collision pattern an attacker can exploit.
 #[derive(Debug, Default)]
-struct MyHashMap<K, V> {
-    bucket: Vec<Pair<K, V>>
+struct MyHashMap<K, V, S = RandomState> {
+    bucket: Vec<Pair<K, V>>,
+    // Number of occupied slots. Kept alongside the table so the resize policy
+    // can be checked in O(1) without scanning the bucket.
+    len: usize,
+    // Supplies a fresh hasher for every key we need to place or look up.
+    hash_builder: S
 }
 
-impl<K, V> MyHashMap<K, V> 
-where 
-    K: Default + Clone + HashIt + std::fmt::Debug + PartialEq,
+impl<K, V> MyHashMap<K, V, RandomState>
+where
+    K: Default + Clone + Hash + std::fmt::Debug + PartialEq,
     V: Default + Clone + std::fmt::Debug
 {
     fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K, V, S> MyHashMap<K, V, S>
+where
+    K: Default + Clone + Hash + std::fmt::Debug + PartialEq,
+    V: Default + Clone + std::fmt::Debug,
+    S: BuildHasher
+{
+    fn with_hasher(hash_builder: S) -> Self {
         Self {
-            bucket: vec![Pair::default(); INITIAL_CAPACITY] // Container of pairs 
+            bucket: vec![Pair::default(); INITIAL_CAPACITY], // Container of pairs
+            len: 0,
+            hash_builder
         }
     }
 
-    fn with_capacity(capacity: usize) -> Self {
+    fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        // The bitmask indexing relies on the capacity being a power of two,
+        // so round the request up to the next one.
         Self {
-            bucket: vec![Pair::default(); capacity]
+            bucket: vec![Pair::default(); to_power_of_two(capacity)],
+            len: 0,
+            hash_builder
         }
     }
 
-    fn increase_capacity(&mut self) {
-        let mut new_map = Self::with_capacity(self.bucket.len() * 2);
+    // Runs the key through a freshly built hasher and returns the finished value.
+    fn hash(&self, key: &K) -> usize {
+        let mut hasher = self.hash_builder.build_hasher();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
 
-        for pair in self.bucket.iter() {
-            new_map.insert(pair.key.clone(), pair.value.clone());
+    // Fallible growth path. Every capacity change routes through here so the
+    // infallible callers stay a thin unwrap over it. Returns `CapacityOverflow`
+    // when doubling would exceed `usize`, and `AllocError` when the allocator
+    // refuses the new backing storage, rather than aborting the process.
+    fn try_increase_capacity(&mut self) -> Result<(), TryReserveError> {
+        let new_cap = self.bucket
+            .len()
+            .checked_mul(2)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        let mut new_bucket: Vec<Pair<K, V>> = Vec::new();
+        new_bucket
+            .try_reserve_exact(new_cap)
+            .map_err(|_| TryReserveError::AllocError)?;
+        new_bucket.resize(new_cap, Pair::default());
+
+        // Reuse the existing builder so previously stored keys land on the same
+        // slots they would for a fresh lookup; only the table is swapped out.
+        let old = std::mem::replace(&mut self.bucket, new_bucket);
+        self.len = 0;
+
+        for pair in old {
+            if pair.state == SlotState::Occupied {
+                self.insert(pair.key, pair.value);
             }
+        }
 
-        *self = new_map;
+        Ok(())
     }
 
-    fn insert(&mut self, key: K, value: V) -> Option<V> {
+    fn increase_capacity(&mut self) {
+        self.try_increase_capacity()
+            .expect("allocation failure while growing the table");
+    }
+
+    // Ensures there is room for `additional` more pairs without crossing the load
+    // factor, reporting an allocation error instead of panicking on failure.
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self.len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        while required as f64 > self.bucket.len() as f64 * MAX_LOAD_FACTOR {
+            self.try_increase_capacity()?;
+        }
+
+        Ok(())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional)
+            .expect("allocation failure in reserve");
+    }
 
-        let mut index = key.hash() % self.bucket.len();
-        
-        if !self.bucket[index].is_occupied {
-            self.bucket[index].key = key;
-            self.bucket[index].value = value;
-            self.bucket[index].is_occupied = true;
-            return None;
+    // Insertion that surfaces allocation failure as a `TryReserveError` rather than
+    // growing-and-panicking. The infallible `insert` is just this unwrapped.
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, TryReserveError> {
 
-        } 
+        // Grow before we cross the load factor so the probe chain below always
+        // terminates on an empty slot rather than wrapping the whole table.
+        if (self.len + 1) as f64 > self.bucket.len() as f64 * MAX_LOAD_FACTOR {
+            self.try_increase_capacity()?;
+        }
+
+        // Power-of-two capacity lets us replace `hash % len` with a cheap mask.
+        let mask = self.bucket.len() - 1;
+        let mut index = self.hash(&key) & mask;
 
-        // Using open addressing (linear probing) to make insertions and give a pair an index. 
-        // Linear probing is quite simple and effective 
+        // Using open addressing (linear probing) to make insertions and give a pair an index.
+        // Linear probing is quite simple and effective
         // as it can handle collisions well by just shifting the pair to unoccupied index.
         // Chain probing can be used to link keys under the same hash. Can be accessed through key as same as linear probing
 
         //   H(k)  -> Index
         // |--------------------------------|
-        // | (k,v) | (k,v) | (k,v) | (k,v)  | ------> Linear probing 
+        // | (k,v) | (k,v) | (k,v) | (k,v)  | ------> Linear probing
         // |--------------------------------|
 
 
         //   H(k)  -> Index
         // |--------------------------------|
-        // | (k,v) | (k,v) | (k,v) | (k,v)  | 
+        // | (k,v) | (k,v) | (k,v) | (k,v)  |
         // |--------------------------------|
         //    /\
         //    ||
         //    ||
         // |-------|
-        // | (k,v) |        ------> Chain probing 
+        // | (k,v) |        ------> Chain probing
         // |-------|
 
-        let start = index;
-        loop {
-            index = (index + 1) % self.bucket.len();
 
-            if self.bucket[index].key == key {
+        loop {
+            if self.bucket[index].state == SlotState::Occupied && self.bucket[index].key == key {
                 let old_value = self.bucket[index].value.clone();
                 self.bucket[index].value = value;
-                return Some(old_value);
+                return Ok(Some(old_value));
             }
 
-            if !self.bucket[index].is_occupied {
+            if self.bucket[index].state != SlotState::Occupied {
                 self.bucket[index].key = key;
                 self.bucket[index].value = value;
-                self.bucket[index].is_occupied = true;
-                return None;
+                self.bucket[index].state = SlotState::Occupied;
+                self.len += 1;
+                return Ok(None);
+            }
+
+            index = (index + 1) & mask;
+        }
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.try_insert(key, value)
+            .expect("allocation failure during insert")
+    }
+
+    // Looks the key up once and hands back a handle to its slot, so callers can
+    // branch on presence and insert/modify without hashing a second time. An
+    // occupied key is returned straight away; only the vacant path grows the table,
+    // so the common `and_modify` counter pattern never triggers a rehash. When a
+    // grow does happen we re-probe so the slot captured in the returned entry stays
+    // valid for the subsequent `or_insert`.
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        let mask = self.bucket.len() - 1;
+        let mut index = self.hash(&key) & mask;
+
+        loop {
+            match self.bucket[index].state {
+                SlotState::Empty => break,
+                SlotState::Occupied if self.bucket[index].key == key => {
+                    return Entry::Occupied(OccupiedEntry { map: self, index });
+                }
+                _ => index = (index + 1) & mask
             }
+        }
 
-            if start == index {
-                self.increase_capacity();
+        // Vacant: an insert will follow, so ensure capacity now and re-probe if the
+        // grow moved things around.
+        if (self.len + 1) as f64 > self.bucket.len() as f64 * MAX_LOAD_FACTOR {
+            self.increase_capacity();
+            let mask = self.bucket.len() - 1;
+            index = self.hash(&key) & mask;
+            while self.bucket[index].state == SlotState::Occupied {
+                index = (index + 1) & mask;
             }
-        }      
-    } 
+        }
+
+        Entry::Vacant(VacantEntry { map: self, key, index })
+    }
+
+    // Borrowing iterator over the live `(key, value)` pairs. Empty slots are
+    // skipped, so the order reflects slot layout, not insertion order.
+    fn iter(&self) -> Iter<'_, K, V> {
+        Iter { inner: self.bucket.iter() }
+    }
+
+    // Like `iter` but hands out mutable references to the values.
+    fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut { inner: self.bucket.iter_mut() }
+    }
 
-    fn extend(&mut self, how_much: usize) {
-        self.bucket.reserve_exact(how_much);
+    fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.iter_mut().map(|(_, value)| value)
     }
 
     fn remove(&mut self, key: K) -> Option<Pair<K, V>>{
 
-        let index = key.hash() % self.bucket.len();
+        let mask = self.bucket.len() - 1;
+        let slot = self.find_slot(&key)?;
+
+        let removed = self.bucket[slot].clone();
+
+        // Backward-shift deletion: scan forward from the hole and pull back each
+        // following element whose ideal slot lies outside the open interval
+        // `(hole, next]`, i.e. one whose probe chain passes through the hole. We
+        // must keep scanning past correctly-placed elements (those whose ideal is
+        // inside the interval) rather than stopping at the first one, since a later
+        // element can still need to shift back into the hole. The walk stops only at
+        // the first non-`Occupied` slot, which is where the chain genuinely ends.
+        let mut hole = slot;
+        let mut next = (hole + 1) & mask;
+        while self.bucket[next].state == SlotState::Occupied {
+            let ideal = self.hash(&self.bucket[next].key) & mask;
+
+            // Is `ideal` cyclically within `(hole, next]`? If so, moving `next` back
+            // to `hole` would place it before its ideal slot and break lookups.
+            let in_range = if hole <= next {
+                hole < ideal && ideal <= next
+            } else {
+                hole < ideal || ideal <= next
+            };
 
-        if self.bucket[index].is_occupied {
-            let removed = &self.bucket[index].clone();
-            self.bucket[index].key = K::default();
-            self.bucket[index].value = V::default();
-            self.bucket[index].is_occupied = false;
-            return Some(removed.clone());
-        } else {
-            panic!("Given Key does not exist.")
+            if !in_range {
+                self.bucket[hole] = self.bucket[next].clone();
+                hole = next;
+            }
+
+            next = (next + 1) & mask;
         }
 
+        self.bucket[hole] = Pair::default();
+        self.len -= 1;
+
+        Some(removed)
     }
 
     fn get(&self, key: &K) -> Option<&V> {
+        self.find_slot(key).map(|index| &self.bucket[index].value)
+    }
 
-        let index = key.hash() % self.bucket.len();
+    // Follows the probe chain from the key's ideal slot, returning the index of the
+    // matching live pair or `None` once an empty slot is reached. Backward-shift
+    // deletion keeps chains contiguous, so a run of occupied slots is never broken
+    // before the key would be found.
+    fn find_slot(&self, key: &K) -> Option<usize> {
+        let mask = self.bucket.len() - 1;
+        let mut index = self.hash(key) & mask;
 
-        if self.bucket[index].is_occupied {
-            Some(&self.bucket[index].value)
-        } else {
-            None
+        loop {
+            match self.bucket[index].state {
+                SlotState::Empty => return None,
+                SlotState::Occupied if self.bucket[index].key == *key => return Some(index),
+                _ => index = (index + 1) & mask
+            }
         }
     }
 
     fn print_it(&self) {
-        for (index, pair) in self.bucket.iter().enumerate() {   
-            if pair.is_occupied {      
+        for (index, pair) in self.bucket.iter().enumerate() {
+            if pair.state == SlotState::Occupied {
             println!("{:?}: {:?}   index: {index}", pair.key, pair.value);
             } else {
                 println!("---"); // Unoccupied
@@ -150,33 +342,372 @@ where
     }
 }
 
-trait HashIt {
+// Rounds a requested capacity up to the next power of two so the table can be
+// indexed with a bitmask. A zero request still yields a usable single-slot table.
+fn to_power_of_two(capacity: usize) -> usize {
+    if capacity <= 1 {
+        1
+    } else {
+        capacity.next_power_of_two()
+    }
+}
+
+// Why a reservation or insertion could not be satisfied, following the
+// `CollectionAllocErr` model: either the requested capacity overflowed the address
+// space, or the allocator itself declined the request. Returning this lets
+// memory-constrained callers recover instead of taking the process down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TryReserveError {
+    // The new capacity would not fit in a `usize`.
+    CapacityOverflow,
+    // The allocator could not grow the backing store.
+    AllocError
+}
+
+impl std::fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryReserveError::CapacityOverflow => {
+                f.write_str("requested capacity overflows the address space")
+            }
+            TryReserveError::AllocError => {
+                f.write_str("allocator failed to grow the table")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TryReserveError {}
 
-    // The key must not be moved while hashing it. So reference to key is passed.
-    fn hash(&self) -> usize; 
+// Shared-reference iterator over the live pairs of a map.
+struct Iter<'a, K, V> {
+    inner: std::slice::Iter<'a, Pair<K, V>>
 }
 
-impl HashIt for usize {
-    fn hash(&self) -> usize {
-        let a = 2654435769;
-        let product = (self.wrapping_mul(a)) >> (64 - 32);
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
 
-        product as usize        
+    fn next(&mut self) -> Option<Self::Item> {
+        for pair in self.inner.by_ref() {
+            if pair.state == SlotState::Occupied {
+                return Some((&pair.key, &pair.value));
+            }
+        }
+        None
     }
 }
 
-impl HashIt for String {
-    fn hash(&self) -> usize {
-        // Daniel J. Bernstein's djb2 algorithm
-        // Better suited especially for strings
-        // Reason to use 5381 is that it is seemed to have fewer collisions and significant change in the hash if a bit flips 
-        // which are essential to be considered a good hash function.
-        let mut hash: usize = 5381;
- 
-        for c in self.bytes() {
-            hash = (hash << 5).wrapping_add(hash).wrapping_add(c as usize);
+// Mutable-value iterator over the live pairs of a map.
+struct IterMut<'a, K, V> {
+    inner: std::slice::IterMut<'a, Pair<K, V>>
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for pair in self.inner.by_ref() {
+            if pair.state == SlotState::Occupied {
+                return Some((&pair.key, &mut pair.value));
+            }
+        }
+        None
+    }
+}
+
+// Owning iterator returned by `into_iter`, yielding each live pair by value.
+struct IntoIter<K, V> {
+    inner: std::vec::IntoIter<Pair<K, V>>
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for pair in self.inner.by_ref() {
+            if pair.state == SlotState::Occupied {
+                return Some((pair.key, pair.value));
+            }
         }
-        hash
+        None
+    }
+}
+
+impl<K, V, S> IntoIterator for MyHashMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { inner: self.bucket.into_iter() }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a MyHashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter { inner: self.bucket.iter() }
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for MyHashMap<K, V, S>
+where
+    K: Default + Clone + Hash + std::fmt::Debug + PartialEq,
+    V: Default + Clone + std::fmt::Debug,
+    S: BuildHasher + Default
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut map = Self::with_hasher(S::default());
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for MyHashMap<K, V, S>
+where
+    K: Default + Clone + Hash + std::fmt::Debug + PartialEq,
+    V: Default + Clone + std::fmt::Debug,
+    S: BuildHasher
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+// A view into a single slot, returned by `entry`. It remembers the slot found
+// during the lookup so the eventual insert writes straight to it.
+enum Entry<'a, K, V, S> {
+    // The key is already present; the handle points at its live slot.
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    // The key is absent; the handle owns the key and the empty slot to fill.
+    Vacant(VacantEntry<'a, K, V, S>)
+}
+
+struct OccupiedEntry<'a, K, V, S> {
+    map: &'a mut MyHashMap<K, V, S>,
+    index: usize
+}
+
+struct VacantEntry<'a, K, V, S> {
+    map: &'a mut MyHashMap<K, V, S>,
+    key: K,
+    index: usize
+}
+
+impl<'a, K, V, S> Entry<'a, K, V, S>
+where
+    V: Default
+{
+    // Returns a reference to the value, inserting `default` first if vacant.
+    fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => &mut entry.map.bucket[entry.index].value,
+            Entry::Vacant(entry) => entry.insert(default)
+        }
+    }
+
+    // Like `or_insert` but only builds the default when the slot is actually empty.
+    fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => &mut entry.map.bucket[entry.index].value,
+            Entry::Vacant(entry) => entry.insert(default())
+        }
+    }
+
+    // Runs `f` on the value when the key is present, then returns the entry so the
+    // call can be chained with `or_insert`.
+    fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(ref mut entry) = self {
+            f(&mut entry.map.bucket[entry.index].value);
+        }
+        self
+    }
+}
+
+impl<'a, K, V, S> VacantEntry<'a, K, V, S> {
+    // Fills the captured slot and returns a reference to the freshly stored value.
+    fn insert(self, value: V) -> &'a mut V {
+        let slot = &mut self.map.bucket[self.index];
+        slot.key = self.key;
+        slot.value = value;
+        slot.state = SlotState::Occupied;
+        self.map.len += 1;
+        &mut self.map.bucket[self.index].value
+    }
+}
+
+// Incremental hashing state. Mirrors std's `Hasher`: keys feed their bytes through
+// `write` and the map reads out a finished index with `finish`.
+trait Hasher {
+    fn write(&mut self, bytes: &[u8]);
+    fn finish(&self) -> usize;
+}
+
+// Builds a fresh `Hasher` for each key. Splitting this from `Hasher` lets a single
+// builder hand out many independently seeded hashers over the life of a map.
+trait BuildHasher {
+    type Hasher: Hasher;
+    fn build_hasher(&self) -> Self::Hasher;
+}
+
+// How a key turns itself into bytes for a hasher. Replaces the old per-type
+// `HashIt` trait: the key no longer owns the algorithm, it only describes its bytes.
+trait Hash {
+    fn hash<H: Hasher>(&self, state: &mut H);
+}
+
+impl Hash for usize {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.to_ne_bytes());
+    }
+}
+
+impl Hash for String {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(self.as_bytes());
+    }
+}
+
+// SipHash-1-3: the same keyed PRF std defaults to. The 128-bit key makes the output
+// unpredictable to anyone who does not hold it, which is what foils collision-flooding.
+// Bytes are buffered and the compression runs in one pass on `finish`, since keys in
+// this crate feed all their bytes in a single `write`.
+struct SipHasher13 {
+    k0: u64,
+    k1: u64,
+    buf: Vec<u8>
+}
+
+impl SipHasher13 {
+    fn new_with_keys(k0: u64, k1: u64) -> Self {
+        Self { k0, k1, buf: Vec::new() }
+    }
+}
+
+#[inline]
+fn sip_round(v0: &mut u64, v1: &mut u64, v2: &mut u64, v3: &mut u64) {
+    *v0 = v0.wrapping_add(*v1); *v1 = v1.rotate_left(13); *v1 ^= *v0; *v0 = v0.rotate_left(32);
+    *v2 = v2.wrapping_add(*v3); *v3 = v3.rotate_left(16); *v3 ^= *v2;
+    *v0 = v0.wrapping_add(*v3); *v3 = v3.rotate_left(21); *v3 ^= *v0;
+    *v2 = v2.wrapping_add(*v1); *v1 = v1.rotate_left(17); *v1 ^= *v2; *v2 = v2.rotate_left(32);
+}
+
+impl Hasher for SipHasher13 {
+    fn write(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    fn finish(&self) -> usize {
+        let mut v0 = self.k0 ^ 0x736f_6d65_7073_6575;
+        let mut v1 = self.k1 ^ 0x646f_7261_6e64_6f6d;
+        let mut v2 = self.k0 ^ 0x6c79_6765_6e65_7261;
+        let mut v3 = self.k1 ^ 0x7465_6462_7974_6573;
+
+        let len = self.buf.len();
+        let mut chunks = self.buf.chunks_exact(8);
+        for chunk in chunks.by_ref() {
+            let m = u64::from_le_bytes(chunk.try_into().unwrap());
+            v3 ^= m;
+            sip_round(&mut v0, &mut v1, &mut v2, &mut v3); // c = 1 compression round
+            v0 ^= m;
+        }
+
+        // Final block: the trailing bytes plus the message length in the top byte.
+        let mut last = (len as u64 & 0xff) << 56;
+        for (i, b) in chunks.remainder().iter().enumerate() {
+            last |= (*b as u64) << (8 * i);
+        }
+        v3 ^= last;
+        sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+        v0 ^= last;
+
+        v2 ^= 0xff;
+        for _ in 0..3 { // d = 3 finalization rounds
+            sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+        }
+
+        (v0 ^ v1 ^ v2 ^ v3) as usize
+    }
+}
+
+// Default hasher builder. Each instance draws a fresh 128-bit key from a per-process
+// randomized source, so two maps do not agree on which keys collide.
+#[derive(Clone, Copy, Debug)]
+struct RandomState {
+    k0: u64,
+    k1: u64
+}
+
+impl RandomState {
+    fn new() -> Self {
+        let (k0, k1) = random_keys();
+        Self { k0, k1 }
+    }
+}
+
+impl Default for RandomState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for RandomState {
+    type Hasher = SipHasher13;
+    fn build_hasher(&self) -> SipHasher13 {
+        SipHasher13::new_with_keys(self.k0, self.k1)
+    }
+}
+
+// Derives a 128-bit key without pulling in an RNG crate. A process-wide base is
+// seeded once from address-space layout and the clock, then each call mixes in a
+// monotonic counter so distinct maps get distinct keys.
+fn random_keys() -> (u64, u64) {
+    static BASE: OnceLock<(u64, u64)> = OnceLock::new();
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+
+    let &(base0, base1) = BASE.get_or_init(|| {
+        // ASLR places this local at an unpredictable address across runs.
+        let entropy = &count as *const u64 as u64;
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        (entropy ^ nanos.rotate_left(17), nanos ^ entropy.rotate_left(32))
+    });
+
+    let k0 = base0 ^ count.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    let k1 = base1 ^ count.wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    (k0, k1)
+}
+
+// Fowler–Noll–Vo 1a: a fast, fully deterministic builder for trusted inputs where
+// the DoS resistance of `RandomState` is unnecessary and its keying is pure overhead.
+#[derive(Clone, Copy, Debug, Default)]
+struct FnvBuildHasher;
+
+struct FnvHasher(u64);
+
+impl BuildHasher for FnvBuildHasher {
+    type Hasher = FnvHasher;
+    fn build_hasher(&self) -> FnvHasher {
+        FnvHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= *b as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> usize {
+        self.0 as usize
     }
 }
 
@@ -194,7 +725,7 @@ fn main() {
 
     let start = Instant::now();
     let mut std_hashmap = HashMap::new();
-    
+
     for i in 0 .. 10000 {
         std_hashmap.insert(format!("{}",i), i*i);
     }
@@ -202,4 +733,4 @@ fn main() {
     println!("STD HASHMAP: {}secs", end);
 
 
-}
\ No newline at end of file
+}